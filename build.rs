@@ -0,0 +1,42 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Embeds build metadata for the `/api/version` endpoint.
+///
+/// Sets `DIDEBAN_GIT_COMMIT` to the short commit hash (or "unknown" outside a git
+/// checkout) and `DIDEBAN_BUILD_TIMESTAMP` in RFC 3339 format.
+///
+/// The timestamp is the time this build script last ran, not the time of every
+/// compilation: the script reruns only when the checked-out commit changes (or the
+/// script itself does), so source-only rebuilds keep the previous timestamp. Set
+/// `SOURCE_DATE_EPOCH` for reproducible builds to use that time instead.
+fn main() {
+    let git_commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let build_time = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.trim().parse::<i64>().ok())
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .unwrap_or_else(chrono::Utc::now);
+    let build_timestamp = build_time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    println!("cargo:rustc-env=DIDEBAN_GIT_COMMIT={}", git_commit);
+    println!("cargo:rustc-env=DIDEBAN_BUILD_TIMESTAMP={}", build_timestamp);
+
+    // Only watch paths that exist: Cargo reruns the script on every build for
+    // missing ones (e.g., in a crate tarball or a Docker context without `.git`)
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    for path in [".git/HEAD", ".git/refs/heads", ".git/packed-refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use actix_web::http::header;
+use actix_web::web::Bytes;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use futures_util::stream;
+use log::LevelFilter;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::config::AppConfig;
+use crate::logger;
+
+/// Registers the JSON API routes under `/api`.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api")
+            .route("/config", web::get().to(get_config))
+            .route("/version", web::get().to(get_version))
+            .route("/logs/stream", web::get().to(stream_logs)),
+    );
+}
+
+/// Build information reported by `GET /api/version`.
+#[derive(Serialize)]
+struct VersionInfo {
+    /// Crate version (e.g., "0.1.0").
+    version: &'static str,
+    /// Short git commit hash the binary was built from, or "unknown".
+    git_commit: &'static str,
+    /// Time the build script last ran (or `SOURCE_DATE_EPOCH`), in RFC 3339 format.
+    build_timestamp: &'static str,
+}
+
+/// Query parameters for `GET /api/logs/stream`.
+#[derive(Deserialize)]
+struct LogStreamQuery {
+    /// Most verbose level to stream (e.g., "warn" streams warnings and errors).
+    level: Option<String>,
+}
+
+/// Checks the request's HTTP Basic credentials against `web_username` and `web_password`.
+///
+/// # Returns
+/// * `true` - The `Authorization` header carries matching credentials.
+/// * `false` - The header is missing, malformed, or the credentials do not match.
+fn is_authorized(req: &HttpRequest, config: &AppConfig) -> bool {
+    let Some(encoded) = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().split_once(' '))
+        // The auth scheme is case-insensitive (RFC 7617)
+        .and_then(|(scheme, encoded)| scheme.eq_ignore_ascii_case("Basic").then_some(encoded))
+    else {
+        return false;
+    };
+
+    let Some(decoded) = STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+    else {
+        return false;
+    };
+
+    match decoded.split_once(':') {
+        // Compare both fields without short-circuiting so timing reveals neither
+        Some((username, password)) => {
+            constant_time_eq(username.as_bytes(), config.web_username.as_bytes())
+                & constant_time_eq(password.as_bytes(), config.web_password.as_bytes())
+        }
+        None => false,
+    }
+}
+
+/// Compares two byte strings in time that depends only on their lengths,
+/// not on the position of the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let len = a.len().max(b.len());
+    let mut diff = a.len() ^ b.len();
+    for i in 0..len {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        diff |= usize::from(x ^ y);
+    }
+    diff == 0
+}
+
+/// Builds the `401 Unauthorized` response that prompts for Basic credentials.
+fn unauthorized() -> HttpResponse {
+    HttpResponse::Unauthorized()
+        .insert_header((header::WWW_AUTHENTICATE, "Basic realm=\"Dideban\""))
+        .finish()
+}
+
+/// `GET /api/config` - Returns the running configuration with secrets redacted.
+async fn get_config(req: HttpRequest, config: web::Data<RwLock<AppConfig>>) -> impl Responder {
+    let config = config.read().unwrap_or_else(|e| e.into_inner());
+    if !is_authorized(&req, &config) {
+        return unauthorized();
+    }
+
+    HttpResponse::Ok().json(&*config)
+}
+
+/// `GET /api/version` - Returns the version and build metadata (no authentication).
+async fn get_version() -> impl Responder {
+    HttpResponse::Ok().json(VersionInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        git_commit: env!("DIDEBAN_GIT_COMMIT"),
+        build_timestamp: env!("DIDEBAN_BUILD_TIMESTAMP"),
+    })
+}
+
+/// `GET /api/logs/stream` - Streams log records as Server-Sent Events.
+///
+/// Each record is sent as a JSON `data:` event. An optional `level` query parameter
+/// (error, warn, info, debug, trace) limits the stream to that level and above;
+/// records below the logger's configured level are never emitted.
+async fn stream_logs(
+    req: HttpRequest,
+    config: web::Data<RwLock<AppConfig>>,
+    query: web::Query<LogStreamQuery>,
+) -> impl Responder {
+    if !is_authorized(&req, &config.read().unwrap_or_else(|e| e.into_inner())) {
+        return unauthorized();
+    }
+
+    // Accept the same names as `log_level`, not everything `LevelFilter` parses (e.g., "off")
+    let max_level = match query.level.as_deref() {
+        None => LevelFilter::Trace,
+        Some("error") => LevelFilter::Error,
+        Some("warn") => LevelFilter::Warn,
+        Some("info") => LevelFilter::Info,
+        Some("debug") => LevelFilter::Debug,
+        Some("trace") => LevelFilter::Trace,
+        Some(_) => {
+            return HttpResponse::BadRequest()
+                .body("Invalid level. Must be one of: error, warn, info, debug, trace")
+        }
+    };
+
+    let events = stream::unfold(logger::subscribe(), move |mut receiver| async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(record) if record.level <= max_level => match serde_json::to_string(&record) {
+                    Ok(json) => format!("data: {}\n\n", json),
+                    Err(_) => continue,
+                },
+                Ok(_) => continue,
+                // Tell the client it fell behind instead of silently dropping records
+                Err(RecvError::Lagged(missed)) => format!(": missed {} log records\n\n", missed),
+                Err(RecvError::Closed) => return None,
+            };
+            return Some((Ok::<_, actix_web::Error>(Bytes::from(event)), receiver));
+        }
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::MessageBody;
+    use actix_web::test::{self, TestRequest};
+    use actix_web::{http::StatusCode, App};
+    use log::{Level, Log, Record};
+    use std::time::Duration;
+
+    fn config() -> AppConfig {
+        let mut config = AppConfig::default();
+        config.web_username = "admin".to_string();
+        config.web_password = "s3cret".to_string();
+        config
+    }
+
+    fn request(authorization: &str) -> HttpRequest {
+        TestRequest::default()
+            .insert_header((header::AUTHORIZATION, authorization))
+            .to_http_request()
+    }
+
+    #[test]
+    fn constant_time_eq_matches_only_equal_inputs() {
+        assert!(constant_time_eq(b"s3cret", b"s3cret"));
+        assert!(constant_time_eq(b"", b""));
+        assert!(!constant_time_eq(b"s3cret", b"s3creT"));
+        assert!(!constant_time_eq(b"s3cret", b"s3cre"));
+        assert!(!constant_time_eq(b"", b"s3cret"));
+    }
+
+    #[test]
+    fn is_authorized_accepts_matching_credentials_with_any_scheme_case() {
+        let encoded = STANDARD.encode("admin:s3cret");
+        for scheme in ["Basic", "basic", "BASIC"] {
+            assert!(is_authorized(&request(&format!("{} {}", scheme, encoded)), &config()));
+        }
+    }
+
+    #[test]
+    fn is_authorized_rejects_wrong_or_malformed_credentials() {
+        let config = config();
+        assert!(!is_authorized(&request(&format!("Basic {}", STANDARD.encode("admin:wrong"))), &config));
+        assert!(!is_authorized(&request(&format!("Basic {}", STANDARD.encode("root:s3cret"))), &config));
+        assert!(!is_authorized(&request(&format!("Bearer {}", STANDARD.encode("admin:s3cret"))), &config));
+        assert!(!is_authorized(&request("Basic not-base64!"), &config));
+        assert!(!is_authorized(&TestRequest::default().to_http_request(), &config));
+    }
+
+    #[actix_web::test]
+    async fn get_config_requires_credentials_and_redacts_secrets() {
+        let mut config = config();
+        config.bale_token = "123:bot-token".to_string();
+        let app = test::init_service(
+            App::new().app_data(web::Data::new(RwLock::new(config))).configure(configure),
+        )
+        .await;
+
+        let req = TestRequest::get().uri("/api/config").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = TestRequest::get()
+            .uri("/api/config")
+            .insert_header((header::AUTHORIZATION, format!("Basic {}", STANDARD.encode("admin:s3cret"))))
+            .to_request();
+        let json: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(json["web_username"], "admin");
+        assert_eq!(json["web_password"], "***");
+        assert_eq!(json["bale_token"], "***");
+    }
+
+    /// Returns a `GET /api/logs/stream` request with the given query and valid credentials.
+    fn stream_request(query: &str) -> TestRequest {
+        TestRequest::get()
+            .uri(&format!("/api/logs/stream{}", query))
+            .insert_header((header::AUTHORIZATION, format!("Basic {}", STANDARD.encode("admin:s3cret"))))
+    }
+
+    #[actix_web::test]
+    async fn stream_logs_rejects_missing_credentials_and_unknown_levels() {
+        let app = test::init_service(
+            App::new().app_data(web::Data::new(RwLock::new(config()))).configure(configure),
+        )
+        .await;
+
+        let req = TestRequest::get().uri("/api/logs/stream").to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNAUTHORIZED);
+
+        for level in ["off", "INFO", "verbose"] {
+            let req = stream_request(&format!("?level={}", level)).to_request();
+            assert_eq!(test::call_service(&app, req).await.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[actix_web::test]
+    async fn stream_logs_sends_only_records_at_the_requested_level() {
+        let app = test::init_service(
+            App::new().app_data(web::Data::new(RwLock::new(config()))).configure(configure),
+        )
+        .await;
+        let resp = test::call_service(&app, stream_request("?level=warn").to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // The handler has subscribed, so these records reach the stream
+        let logger = logger::ConsoleLogger::new(LevelFilter::Trace, logger::LogFormat::Logfmt);
+        for (level, message) in [(Level::Debug, "too verbose"), (Level::Warn, "disk almost full")] {
+            logger.log(
+                &Record::builder()
+                    .level(level)
+                    .target("dideban_api_test")
+                    .args(format_args!("{}", message))
+                    .build(),
+            );
+        }
+
+        let mut body = std::pin::pin!(resp.into_body());
+        let event = actix_web::rt::time::timeout(
+            Duration::from_secs(5),
+            std::future::poll_fn(|cx| body.as_mut().poll_next(cx)),
+        )
+        .await
+        .expect("an event is sent")
+        .expect("the stream is open")
+        .expect("the event is readable");
+        let event = String::from_utf8(event.to_vec()).expect("events are UTF-8");
+
+        assert!(event.starts_with("data: "), "unexpected event: {}", event);
+        assert!(event.contains("disk almost full"), "unexpected event: {}", event);
+    }
+}
@@ -0,0 +1,47 @@
+use actix_web::http::header;
+use actix_web::{web, HttpResponse, Responder};
+
+/// An asset compiled into the binary.
+struct Asset {
+    /// File name under `/static/` (e.g., "favicon.ico").
+    name: &'static str,
+    /// MIME type sent in the `Content-Type` header.
+    content_type: &'static str,
+    /// Raw file contents.
+    body: &'static [u8],
+}
+
+/// Static assets embedded at compile time so Dideban ships as a single binary
+/// and the dashboard works without external CDNs or network access.
+const ASSETS: &[Asset] = &[Asset {
+    name: "favicon.ico",
+    content_type: "image/x-icon",
+    body: include_bytes!("../assets/favicon.ico"),
+}];
+
+/// Registers `/favicon.ico` and the `/static/*` asset routes.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/favicon.ico", web::get().to(favicon))
+        .route("/static/{name}", web::get().to(static_asset));
+}
+
+/// Builds the response for an embedded asset, or `404 Not Found` if it does not exist.
+fn serve(name: &str) -> HttpResponse {
+    match ASSETS.iter().find(|asset| asset.name == name) {
+        Some(asset) => HttpResponse::Ok()
+            .content_type(asset.content_type)
+            .insert_header((header::CACHE_CONTROL, "public, max-age=86400"))
+            .body(asset.body),
+        None => HttpResponse::NotFound().finish(),
+    }
+}
+
+/// `GET /favicon.ico` - Returns the embedded favicon.
+async fn favicon() -> impl Responder {
+    serve("favicon.ico")
+}
+
+/// `GET /static/{name}` - Returns an embedded asset by file name.
+async fn static_asset(name: web::Path<String>) -> impl Responder {
+    serve(&name)
+}
@@ -1,102 +1,690 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 use directories::ProjectDirs;
+use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// Errors that can occur while loading the configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The platform configuration directory could not be determined.
+    NotFound,
+    /// A file (the config file or a secret file) could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The config file is not valid TOML or does not match the expected structure.
+    Parse(toml::de::Error),
+    /// A field has an invalid value: `(field, reason)`.
+    Validation(&'static str, String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NotFound => write!(f, "Could not determine config directory"),
+            ConfigError::Io { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            ConfigError::Parse(e) => write!(f, "Failed to parse config file: {}", e),
+            ConfigError::Validation(field, reason) => write!(f, "Invalid {}: {}", field, reason),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            ConfigError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 /// Configuration structure for the Dideban application.
 ///
 /// This struct holds all configuration parameters for the website and service monitoring tool,
 /// loaded from a TOML configuration file or default values.
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone)]
 pub struct AppConfig {
     /// List of domains to monitor (e.g., ["https://example.com", "https://google.com"]).
+    ///
+    /// URLs generated from `endpoints` are appended to this list when the file is loaded.
+    #[serde(default)]
     pub domains: Vec<String>,
+    /// Groups of endpoints sharing a base URL, expanded into `domains` on load.
+    #[serde(default)]
+    pub endpoints: Vec<EndpointGroup>,
     /// Interval between monitoring checks, in seconds.
     pub interval: u64,
     /// Host address for the web server (e.g., "0.0.0.0").
     pub server_host: String,
     /// Port for the web server (e.g., 8000).
     pub server_port: u16,
+    /// HTTP keep-alive timeout for the web server, in seconds (0 disables keep-alive).
+    ///
+    /// Defaults to 5, matching Actix Web's default.
+    #[serde(default = "default_server_keep_alive")]
+    pub server_keep_alive: u64,
+    /// Time a client has to send the request head before the web server drops the
+    /// connection, in seconds (0 disables the timeout).
+    ///
+    /// Defaults to 5, matching Actix Web's default.
+    #[serde(default = "default_server_client_timeout")]
+    pub server_client_timeout: u64,
+    /// Path prefix for all routes when served behind a reverse proxy (e.g., "/dideban").
+    ///
+    /// Empty (the default) serves from the root. A trailing slash is ignored.
+    /// Must start with '/' and may not contain '{', '}', or whitespace.
+    #[serde(default)]
+    pub base_path: String,
     /// Enable Bale notifications.
     pub enable_bale: bool,
     /// Bale bot token for notifications.
+    #[serde(default, serialize_with = "redact")]
     pub bale_token: String,
+    /// Path to a file containing the Bale bot token (e.g., a Docker secret).
+    ///
+    /// When set, the file contents take precedence over `bale_token`.
+    #[serde(default)]
+    pub bale_token_file: Option<String>,
     /// Bale chat ID for notifications.
     pub bale_chat_id: String,
     /// Username for web interface authentication.
     pub web_username: String,
     /// Password for web interface authentication.
+    #[serde(default, serialize_with = "redact")]
     pub web_password: String,
+    /// Path to a file containing the web interface password (e.g., a Docker secret).
+    ///
+    /// When set, the file contents take precedence over `web_password`.
+    #[serde(default)]
+    pub web_password_file: Option<String>,
     /// Path to the database file (e.g., "dideban.db").
     pub db_path: String,
     /// Logging level (e.g., "error", "warn", "info", "debug", "trace").
     pub log_level: String,
+    /// Log output format ("text" or "logfmt"). Defaults to "text".
+    #[serde(default = "default_log_format")]
+    pub log_format: String,
+    /// Where this configuration was loaded from.
+    #[serde(skip)]
+    pub source: ConfigSource,
+}
+
+/// Origin of a loaded `AppConfig`.
+///
+/// The logger is not yet initialized while the config is loaded (it depends on
+/// `log_level`), so the outcome is recorded here and logged by `DidebanBuilder::build`.
+#[derive(Clone, Debug, Default)]
+pub enum ConfigSource {
+    /// Constructed in code rather than loaded (e.g., when embedding Dideban).
+    #[default]
+    Inline,
+    /// Loaded from the config file at this path.
+    File(PathBuf),
+    /// No config file existed at this path, so default values were used.
+    Defaults(PathBuf),
+}
+
+/// A base URL combined with a list of paths, each producing one monitored URL.
+///
+/// For example, `base = "https://api.example.com"` with `paths = ["/health", "/ready"]`
+/// expands to `https://api.example.com/health` and `https://api.example.com/ready`.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct EndpointGroup {
+    /// Base URL shared by all paths (e.g., "https://api.example.com").
+    pub base: String,
+    /// Paths appended to the base URL (e.g., ["/health", "/ready"]).
+    pub paths: Vec<String>,
+}
+
+impl EndpointGroup {
+    /// Returns the full URLs produced by joining the base with each path.
+    ///
+    /// A trailing slash on the base and a missing leading slash on a path are normalized,
+    /// so `"https://a.com/"` + `"health"` yields `"https://a.com/health"`.
+    pub fn urls(&self) -> Vec<String> {
+        let base = self.base.trim_end_matches('/');
+        self.paths
+            .iter()
+            .map(|path| format!("{}/{}", base, path.trim_start_matches('/')))
+            .collect()
+    }
+}
+
+/// Reads a secret from a file, trimming the trailing newline most editors and
+/// secret managers append.
+fn read_secret_file(path: &str) -> Result<String, ConfigError> {
+    fs::read_to_string(path)
+        .map(|content| content.trim_end_matches(['\r', '\n']).to_string())
+        .map_err(|source| ConfigError::Io { path: PathBuf::from(path), source })
+}
+
+/// Checks that a monitored URL is absolute, uses http or https, and has a host.
+fn validate_url(value: &str) -> Result<(), String> {
+    let url = Url::parse(value).map_err(|e| format!("not a valid URL ({})", e))?;
+    if !["http", "https"].contains(&url.scheme()) {
+        return Err(format!("unsupported scheme '{}', expected http or https", url.scheme()));
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err("missing host".to_string());
+    }
+    Ok(())
+}
+
+/// Serializes a secret field as `"***"` so it never leaves the process.
+///
+/// Empty values are kept empty to show that the secret is not configured.
+fn redact<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    if value.is_empty() {
+        serializer.serialize_str("")
+    } else {
+        serializer.serialize_str("***")
+    }
+}
+
+/// Default value for `server_keep_alive` when omitted from the config file.
+fn default_server_keep_alive() -> u64 {
+    5
+}
+
+/// Default value for `server_client_timeout` when omitted from the config file.
+fn default_server_client_timeout() -> u64 {
+    5
+}
+
+/// Default value for `log_format` when omitted from the config file.
+fn default_log_format() -> String {
+    "text".to_string()
 }
 
 impl AppConfig {
-    /// Loads configuration from a TOML file.
+    /// Returns the path of the configuration file.
     ///
-    /// In debug mode, reads from `./config.toml` in the project directory.
-    /// In release mode, reads from the system configuration directory
+    /// In debug mode, this is `./config.toml` in the project directory.
+    /// In release mode, it is in the system configuration directory
     /// (e.g., `/etc/dideban/config.toml` on Linux or `%APPDATA%\dideban\config.toml` on Windows).
+    pub fn default_path() -> Result<PathBuf, ConfigError> {
+        if cfg!(debug_assertions) {
+            Ok(Path::new("config.toml").to_path_buf())
+        } else {
+            let proj_dirs = ProjectDirs::from("com", "dideban", "dideban")
+                .ok_or(ConfigError::NotFound)?;
+            Ok(proj_dirs.config_dir().join("config.toml"))
+        }
+    }
+
+    /// Loads configuration from the TOML file at `default_path`.
+    ///
+    /// If the file does not exist, falls back to default values.
+    /// The absolute path attempted and the outcome are recorded in `source`.
+    ///
+    /// # Returns
+    /// - `Ok(AppConfig)`: Successfully loaded configuration.
+    /// - `Err(ConfigError)`: Reading, parsing, or validating the file failed
+    ///   (the first validation problem found is returned).
+    pub fn from_file() -> Result<Self, ConfigError> {
+        let config_path = Self::default_path()?;
+        // Report the absolute path so a relative or mistyped location is obvious in logs
+        let config_path = std::path::absolute(&config_path).unwrap_or(config_path);
+
+        Self::from_path(&config_path)
+    }
+
+    /// Loads and validates the configuration file at `path`.
+    ///
     /// If the file does not exist, falls back to default values.
     ///
     /// # Returns
     /// - `Ok(AppConfig)`: Successfully loaded configuration.
-    /// - `Err(String)`: Error message if file reading or parsing fails.
-    pub fn from_file() -> Result<Self, String> {
-        let config_path = if cfg!(debug_assertions) {
-            Path::new("config.toml").to_path_buf()
+    /// - `Err(ConfigError)`: Reading, parsing, or validating the file failed
+    ///   (the first validation problem found is returned).
+    pub fn from_path(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self {
+                source: ConfigSource::Defaults(path.to_path_buf()),
+                ..Self::default()
+            });
+        }
+
+        Self::load_validated(path)
+    }
+
+    /// Re-reads the configuration from the path it was originally loaded from.
+    ///
+    /// Unlike `from_path`, a missing file is an error, so a moved or deleted config
+    /// never replaces the running one with default values.
+    ///
+    /// # Returns
+    /// - `Some(Ok(AppConfig))`: The freshly loaded configuration.
+    /// - `Some(Err(ConfigError))`: Reading, parsing, or validating the file failed.
+    /// - `None`: The configuration was constructed in code and has no file to reload.
+    pub fn reload(&self) -> Option<Result<Self, ConfigError>> {
+        match &self.source {
+            ConfigSource::File(path) | ConfigSource::Defaults(path) => Some(Self::load_validated(path)),
+            ConfigSource::Inline => None,
+        }
+    }
+
+    /// Returns the names of settings that differ from `other` but only take
+    /// effect on restart (server binding and logger settings).
+    pub fn restart_required_changes(&self, other: &AppConfig) -> Vec<&'static str> {
+        [
+            ("server_host", self.server_host != other.server_host),
+            ("server_port", self.server_port != other.server_port),
+            ("server_keep_alive", self.server_keep_alive != other.server_keep_alive),
+            ("server_client_timeout", self.server_client_timeout != other.server_client_timeout),
+            ("base_path", self.base_path != other.base_path),
+            ("log_level", self.log_level != other.log_level),
+            ("log_format", self.log_format != other.log_format),
+        ]
+        .into_iter()
+        .filter_map(|(name, changed)| changed.then_some(name))
+        .collect()
+    }
+
+    /// Copies the restart-only settings (see `restart_required_changes`) from
+    /// `running`, so a reloaded config reports what is actually in effect.
+    pub fn keep_restart_only_settings(&mut self, running: &AppConfig) {
+        self.server_host = running.server_host.clone();
+        self.server_port = running.server_port;
+        self.server_keep_alive = running.server_keep_alive;
+        self.server_client_timeout = running.server_client_timeout;
+        self.base_path = running.base_path.clone();
+        self.log_level = running.log_level.clone();
+        self.log_format = running.log_format.clone();
+    }
+
+    /// Loads and validates the configuration file at `path`, collecting every problem.
+    ///
+    /// Unlike `from_file`, a missing file is an error rather than a fallback to defaults,
+    /// and the advisory `warnings` are reported as problems too.
+    ///
+    /// # Returns
+    /// - `Ok(AppConfig)`: The file is readable, parses, and passes all semantic checks.
+    /// - `Err(Vec<ConfigError>)`: The read or parse error, or all validation problems found.
+    pub fn validate_file(path: &Path) -> Result<Self, Vec<ConfigError>> {
+        let config = Self::load(path).map_err(|e| vec![e])?;
+        let mut errors = config.validate();
+        errors.extend(config.warnings());
+        if errors.is_empty() {
+            Ok(config)
         } else {
-            let proj_dirs = ProjectDirs::from("com", "dideban", "dideban")
-                .ok_or("Could not determine config directory")?;
-            proj_dirs.config_dir().join("config.toml")
-        };
+            Err(errors)
+        }
+    }
 
-        if !config_path.exists() {
-            return Ok(Self::default());
+    /// Loads the file at `path` and returns the first validation problem, if any.
+    fn load_validated(path: &Path) -> Result<Self, ConfigError> {
+        let config = Self::load(path)?;
+        match config.validate().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(config),
         }
+    }
 
-        let config_content = fs::read_to_string(&config_path)
-            .map_err(|e| format!("Failed to read config file {}: {}", config_path.display(), e))?;
+    /// Reads and parses the file at `path`, then resolves it with `resolve`.
+    fn load(path: &Path) -> Result<Self, ConfigError> {
+        let config_content = fs::read_to_string(path)
+            .map_err(|source| ConfigError::Io { path: path.to_path_buf(), source })?;
 
-        let config: AppConfig = toml::from_str(&config_content)
-            .map_err(|e| format!("Failed to parse config file: {}", e))?;
+        let mut config: AppConfig = toml::from_str(&config_content)
+            .map_err(ConfigError::Parse)?;
+        config.source = ConfigSource::File(path.to_path_buf());
+        config.resolve()?;
 
-        // Validate log_level
-        if !["error", "warn", "info", "debug", "trace"].contains(&config.log_level.as_str()) {
-            return Err(format!("Invalid log_level: {}. Must be one of: error, warn, info, debug, trace", config.log_level));
+        Ok(config)
+    }
+
+    /// Prepares a configuration for use: loads secrets from `*_file` paths,
+    /// normalizes `base_path`, and expands endpoint groups into `domains`.
+    ///
+    /// Applied to every loaded file and to configurations passed to
+    /// `Dideban::builder().config(...)`. Running it again is harmless.
+    pub fn resolve(&mut self) -> Result<(), ConfigError> {
+        // Load secrets from files, overriding inline values
+        if let Some(path) = &self.bale_token_file {
+            self.bale_token = read_secret_file(path)?;
+        }
+        if let Some(path) = &self.web_password_file {
+            self.web_password = read_secret_file(path)?;
         }
 
-        // Validate interval
-        if config.interval == 0 {
-            return Err("interval cannot be zero".to_string());
+        // Normalize base_path
+        self.base_path = self.base_path.trim_end_matches('/').to_string();
+
+        // Expand endpoint groups into domains
+        let expanded: Vec<String> = self.endpoints.iter().flat_map(EndpointGroup::urls).collect();
+        for url in expanded {
+            if !self.domains.contains(&url) {
+                self.domains.push(url);
+            }
         }
 
+        Ok(())
+    }
+
+    /// Checks the semantic rules of a loaded configuration.
+    ///
+    /// # Returns
+    /// All problems found, in field order; empty if the configuration is valid.
+    pub fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+
         // Validate domains
-        if config.domains.is_empty() {
-            return Err("domains cannot be empty".to_string());
+        if self.domains.is_empty() {
+            errors.push(ConfigError::Validation("domains", "cannot be empty".to_string()));
         }
 
-        Ok(config)
+        // Validate endpoint groups
+        for group in &self.endpoints {
+            if group.base.trim().is_empty() {
+                errors.push(ConfigError::Validation("endpoints.base", "cannot be empty".to_string()));
+            }
+            if group.paths.is_empty() {
+                errors.push(ConfigError::Validation(
+                    "endpoints.paths",
+                    format!("cannot be empty for base {}", group.base),
+                ));
+            }
+        }
+
+        // Validate interval
+        if self.interval == 0 {
+            errors.push(ConfigError::Validation("interval", "cannot be zero".to_string()));
+        }
+
+        // Validate base_path (it becomes part of the route pattern, where braces
+        // would declare dynamic segments)
+        if !self.base_path.is_empty() && !self.base_path.starts_with('/') {
+            errors.push(ConfigError::Validation(
+                "base_path",
+                format!("{}. Must start with '/'", self.base_path),
+            ));
+        } else if self.base_path.chars().any(|c| c == '{' || c == '}' || c.is_whitespace()) {
+            errors.push(ConfigError::Validation(
+                "base_path",
+                format!("{:?}. Must not contain '{{', '}}', or whitespace", self.base_path),
+            ));
+        }
+
+        // Validate web_password
+        if self.web_password.is_empty() {
+            errors.push(ConfigError::Validation(
+                "web_password",
+                "cannot be empty (set web_password or web_password_file)".to_string(),
+            ));
+        }
+
+        // Validate log_level
+        if !["error", "warn", "info", "debug", "trace"].contains(&self.log_level.as_str()) {
+            errors.push(ConfigError::Validation(
+                "log_level",
+                format!("{}. Must be one of: error, warn, info, debug, trace", self.log_level),
+            ));
+        }
+
+        // Validate log_format
+        if !["text", "logfmt"].contains(&self.log_format.as_str()) {
+            errors.push(ConfigError::Validation(
+                "log_format",
+                format!("{}. Must be one of: text, logfmt", self.log_format),
+            ));
+        }
+
+        errors
+    }
+
+    /// Checks rules that are only advisory at startup but fatal for `--validate`.
+    ///
+    /// These were added after configurations without them were already in use, so
+    /// loading a config that breaks them logs a warning instead of refusing to start.
+    ///
+    /// # Returns
+    /// All problems found, in field order; empty if none.
+    pub fn warnings(&self) -> Vec<ConfigError> {
+        let mut warnings = Vec::new();
+
+        // Check that domains are absolute http(s) URLs
+        for domain in &self.domains {
+            if let Err(reason) = validate_url(domain) {
+                warnings.push(ConfigError::Validation("domains", format!("{}: {}", domain, reason)));
+            }
+        }
+
+        // Check Bale settings
+        if self.enable_bale {
+            if self.bale_token.is_empty() {
+                warnings.push(ConfigError::Validation(
+                    "bale_token",
+                    "cannot be empty when enable_bale is true (set bale_token or bale_token_file)".to_string(),
+                ));
+            }
+            if self.bale_chat_id.is_empty() {
+                warnings.push(ConfigError::Validation(
+                    "bale_chat_id",
+                    "cannot be empty when enable_bale is true".to_string(),
+                ));
+            }
+        }
+
+        warnings
     }
 
     /// Returns default configuration values.
     ///
     /// Used when the configuration file is not found or cannot be parsed.
-    fn default() -> Self {
+    pub(crate) fn default() -> Self {
         Self {
             domains: vec![],
+            endpoints: vec![],
             interval: 60,
             server_host: "127.0.0.1".to_string(),
             server_port: 7000,
+            server_keep_alive: default_server_keep_alive(),
+            server_client_timeout: default_server_client_timeout(),
+            base_path: String::new(),
             enable_bale: false,
             bale_token: String::new(),
+            bale_token_file: None,
             bale_chat_id: String::new(),
             web_username: "admin".to_string(),
             web_password: "admin".to_string(),
+            web_password_file: None,
             db_path: "dideban.db".to_string(),
             log_level: "info".to_string(),
+            log_format: default_log_format(),
+            source: ConfigSource::Inline,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the field names of validation problems, in order.
+    fn fields(errors: &[ConfigError]) -> Vec<&'static str> {
+        errors
+            .iter()
+            .map(|error| match error {
+                ConfigError::Validation(field, _) => *field,
+                _ => "<not a validation error>",
+            })
+            .collect()
+    }
+
+    /// Writes `content` to a fresh file in the temp directory and returns its path.
+    fn temp_config(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("dideban-{}-{}.toml", name, std::process::id()));
+        fs::write(&path, content).expect("write temp config");
+        path
+    }
+
+    const VALID: &str = r#"
+        domains = ["https://example.com"]
+        interval = 60
+        server_host = "127.0.0.1"
+        server_port = 7000
+        enable_bale = false
+        bale_chat_id = ""
+        web_username = "admin"
+        web_password = "admin"
+        db_path = "dideban.db"
+        log_level = "info"
+    "#;
+
+    #[test]
+    fn validate_accepts_valid_config() {
+        let mut config = AppConfig::default();
+        config.domains = vec!["https://example.com".to_string()];
+        assert!(config.validate().is_empty());
+        assert!(config.warnings().is_empty());
+    }
+
+    #[test]
+    fn validate_collects_every_problem() {
+        let mut config = AppConfig::default();
+        config.interval = 0;
+        config.base_path = "dideban".to_string();
+        config.web_password = String::new();
+        config.log_level = "loud".to_string();
+        config.log_format = "json".to_string();
+
+        assert_eq!(
+            fields(&config.validate()),
+            ["domains", "interval", "base_path", "web_password", "log_level", "log_format"]
+        );
+    }
+
+    #[test]
+    fn validate_rejects_route_pattern_characters_in_base_path() {
+        let mut config = AppConfig::default();
+        config.domains = vec!["https://example.com".to_string()];
+        for base_path in ["/{x}", "/dideban/{id:\\d+}", "/my dideban", "/dideban\t"] {
+            config.base_path = base_path.to_string();
+            assert_eq!(fields(&config.validate()), ["base_path"], "{:?} was accepted", base_path);
         }
+
+        config.base_path = "/dideban/v1".to_string();
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn warnings_collect_url_and_bale_problems() {
+        let mut config = AppConfig::default();
+        config.domains = vec![
+            "example.com".to_string(),
+            "ftp://example.com".to_string(),
+            "https://example.com".to_string(),
+        ];
+        config.enable_bale = true;
+
+        assert!(config.validate().is_empty());
+        assert_eq!(
+            fields(&config.warnings()),
+            ["domains", "domains", "bale_token", "bale_chat_id"]
+        );
+    }
+
+    #[test]
+    fn serialize_redacts_secrets() {
+        let mut config = AppConfig::default();
+        config.bale_token = "123:bot-token".to_string();
+        config.web_password = "s3cret".to_string();
+        let json = serde_json::to_value(&config).expect("config serializes");
+        assert_eq!(json["bale_token"], "***");
+        assert_eq!(json["web_password"], "***");
+
+        config.bale_token = String::new();
+        let json = serde_json::to_value(&config).expect("config serializes");
+        assert_eq!(json["bale_token"], "");
+    }
+
+    #[test]
+    fn validate_file_accepts_valid_file() {
+        let path = temp_config("valid", VALID);
+        let result = AppConfig::validate_file(&path);
+        fs::remove_file(&path).ok();
+        assert!(result.is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn validate_file_reports_errors_and_warnings_together() {
+        let content = VALID
+            .replace(r#"["https://example.com"]"#, r#"["example.com"]"#)
+            .replace("interval = 60", "interval = 0")
+            .replace("enable_bale = false", "enable_bale = true")
+            .replace(r#"log_level = "info""#, r#"log_level = "loud""#);
+        let path = temp_config("invalid", &content);
+        let result = AppConfig::validate_file(&path);
+        fs::remove_file(&path).ok();
+
+        let errors = result.err().expect("config is invalid");
+        assert_eq!(
+            fields(&errors),
+            ["interval", "log_level", "domains", "bale_token", "bale_chat_id"]
+        );
+    }
+
+    #[test]
+    fn validate_file_reports_parse_errors() {
+        let path = temp_config("unparsable", "domains = [");
+        let result = AppConfig::validate_file(&path);
+        fs::remove_file(&path).ok();
+
+        let errors = result.err().expect("config is unparsable");
+        assert!(matches!(errors.as_slice(), [ConfigError::Parse(_)]));
+    }
+
+    #[test]
+    fn keep_restart_only_settings_restores_running_values() {
+        let running = AppConfig::default();
+        let mut reloaded = AppConfig::default();
+        reloaded.server_port = running.server_port + 1;
+        reloaded.base_path = "/dideban".to_string();
+        reloaded.log_level = "debug".to_string();
+        reloaded.interval = running.interval + 1;
+        assert_eq!(
+            running.restart_required_changes(&reloaded),
+            ["server_port", "base_path", "log_level"]
+        );
+
+        reloaded.keep_restart_only_settings(&running);
+        assert!(running.restart_required_changes(&reloaded).is_empty());
+        assert_eq!(reloaded.interval, running.interval + 1);
+    }
+
+    #[test]
+    fn reload_fails_when_file_is_missing() {
+        let path = temp_config("moved", VALID);
+        let config = AppConfig::from_path(&path).expect("config is valid");
+        fs::remove_file(&path).ok();
+
+        assert!(matches!(config.reload(), Some(Err(ConfigError::Io { .. }))));
+    }
+
+    #[test]
+    fn reload_reports_validation_problems() {
+        let path = temp_config("reload-invalid", VALID);
+        let config = AppConfig::from_path(&path).expect("config is valid");
+        fs::write(&path, VALID.replace("interval = 60", "interval = 0")).expect("write temp config");
+        let result = config.reload();
+        fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Some(Err(ConfigError::Validation("interval", _)))));
+    }
+
+    #[test]
+    fn from_path_starts_despite_warnings() {
+        let content = VALID
+            .replace(r#"["https://example.com"]"#, r#"["example.com"]"#)
+            .replace("enable_bale = false", "enable_bale = true");
+        let path = temp_config("warnings", &content);
+        let result = AppConfig::from_path(&path);
+        fs::remove_file(&path).ok();
+
+        assert_eq!(result.expect("warnings are not fatal").warnings().len(), 3);
+    }
+}
@@ -0,0 +1,268 @@
+//! Dideban – a fast, lightweight monitor for websites and services.
+//!
+//! The `dideban` binary is a thin wrapper around this crate. Other programs can
+//! embed Dideban by building it from an `AppConfig`:
+//!
+//! ```no_run
+//! use dideban::config::AppConfig;
+//! use dideban::Dideban;
+//!
+//! #[actix_web::main]
+//! async fn main() -> std::io::Result<()> {
+//!     let config = AppConfig::from_file().expect("Failed to load config");
+//!     Dideban::builder().config(config).run().await
+//! }
+//! ```
+
+use actix_web::http::KeepAlive;
+use actix_web::{web, App, HttpServer};
+use log::{info, warn};
+use std::sync::RwLock;
+use std::time::Duration;
+
+pub mod api;
+pub mod assets;
+pub mod config;
+pub mod logger;
+
+use config::{AppConfig, ConfigError, ConfigSource};
+
+/// A configured Dideban instance, ready to run.
+pub struct Dideban {
+    config: AppConfig,
+}
+
+/// Builder for `Dideban`.
+///
+/// If no configuration is supplied, it is loaded with `AppConfig::from_file`.
+#[derive(Default)]
+pub struct DidebanBuilder {
+    config: Option<AppConfig>,
+}
+
+impl Dideban {
+    /// Returns a new `DidebanBuilder`.
+    pub fn builder() -> DidebanBuilder {
+        DidebanBuilder::default()
+    }
+
+    /// Returns the configuration this instance runs with.
+    pub fn config(&self) -> &AppConfig {
+        &self.config
+    }
+
+    /// Starts the Actix Web server with the API and static asset routes under `base_path`.
+    ///
+    /// On Unix, if the configuration was loaded from a file (or its default path),
+    /// `SIGHUP` reloads it from there. Configurations built in code install no
+    /// `SIGHUP` handler, leaving the signal's handling to the host process.
+    ///
+    /// Must be called from within an Actix runtime (e.g., `#[actix_web::main]`).
+    /// Resolves when the server shuts down.
+    pub async fn run(self) -> std::io::Result<()> {
+        // Prepare server address
+        let bind_address = format!("{}:{}", self.config.server_host, self.config.server_port);
+
+        // Log server start message
+        info!("🚀 Server running at http://{}{}/", bind_address, self.config.base_path);
+
+        let keep_alive = match self.config.server_keep_alive {
+            0 => KeepAlive::Disabled,
+            secs => KeepAlive::Timeout(Duration::from_secs(secs)),
+        };
+        let client_timeout = Duration::from_secs(self.config.server_client_timeout);
+
+        let base_path = self.config.base_path.clone();
+        // Configs built in code have no file to reload, so leave the host's SIGHUP alone
+        #[cfg(unix)]
+        let reloadable = !matches!(self.config.source, ConfigSource::Inline);
+        let config_data = web::Data::new(RwLock::new(self.config));
+
+        // Reload the configuration on SIGHUP
+        #[cfg(unix)]
+        if reloadable {
+            actix_web::rt::spawn(reload_on_sighup(config_data.clone()));
+        }
+
+        HttpServer::new(move || {
+            App::new().app_data(config_data.clone()).service(
+                web::scope(&base_path)
+                    .configure(api::configure)
+                    .configure(assets::configure),
+            )
+        })
+        .workers(1)
+        .keep_alive(keep_alive)
+        .client_request_timeout(client_timeout)
+        .bind(&bind_address)?
+        .run()
+        .await
+    }
+}
+
+impl DidebanBuilder {
+    /// Sets the configuration to run with instead of loading it from the config file.
+    pub fn config(mut self, config: AppConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Builds the `Dideban` instance.
+    ///
+    /// A supplied configuration built in code (`ConfigSource::Inline`) is resolved
+    /// (`AppConfig::resolve`) and validated the same way as one loaded from file.
+    /// Loaded configurations are used as-is: files were already validated, and the
+    /// defaults used when no file exists deliberately are not. Advisory
+    /// `AppConfig::warnings` are logged, along with where the configuration came from.
+    ///
+    /// # Returns
+    /// * `Ok(Dideban)` - The instance, ready to run.
+    /// * `Err(ConfigError)` - Loading, resolving, or validating the configuration failed
+    ///   (the first validation problem found is returned).
+    pub fn build(self) -> Result<Dideban, ConfigError> {
+        let config = match self.config {
+            Some(mut config) if matches!(config.source, ConfigSource::Inline) => {
+                config.resolve()?;
+                if let Some(error) = config.validate().into_iter().next() {
+                    return Err(error);
+                }
+                config
+            }
+            Some(config) => config,
+            None => AppConfig::from_file()?,
+        };
+        // Log where the configuration came from
+        match &config.source {
+            ConfigSource::File(path) => info!("📄 Loaded config from {}", path.display()),
+            ConfigSource::Defaults(path) => {
+                info!(
+                    "📄 Config file {} not found; falling back to default configuration",
+                    path.display()
+                );
+                if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty() && !dir.exists()) {
+                    warn!(
+                        "Config directory {} does not exist; create it and place config.toml there",
+                        dir.display()
+                    );
+                }
+            }
+            ConfigSource::Inline => {}
+        }
+        for warning in config.warnings() {
+            warn!("Config problem (run `dideban --validate` for details): {}", warning);
+        }
+        Ok(Dideban { config })
+    }
+
+    /// Builds the instance and runs it until the server shuts down.
+    ///
+    /// Configuration errors are returned as `std::io::Error`.
+    pub async fn run(self) -> std::io::Result<()> {
+        self.build().map_err(std::io::Error::other)?.run().await
+    }
+}
+
+/// Reloads the running configuration each time the process receives `SIGHUP`.
+///
+/// A missing, unreadable, or invalid file is logged and the previous configuration is kept.
+/// Server binding and logger settings are only applied on restart, so their running
+/// values are kept and a warning is logged on every reload while they differ.
+#[cfg(unix)]
+async fn reload_on_sighup(config: web::Data<RwLock<AppConfig>>) {
+    use log::error;
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => hangup,
+        Err(e) => {
+            warn!("Failed to install SIGHUP handler, config reload disabled: {}", e);
+            return;
+        }
+    };
+
+    while hangup.recv().await.is_some() {
+        let current = config.read().unwrap_or_else(|e| e.into_inner()).clone();
+        match current.reload() {
+            Some(Ok(mut reloaded)) => {
+                let restart_required = current.restart_required_changes(&reloaded);
+                if !restart_required.is_empty() {
+                    warn!(
+                        "Changed settings take effect only after a restart: {}",
+                        restart_required.join(", ")
+                    );
+                }
+                // Keep reporting the values still in effect, so later reloads compare against them
+                reloaded.keep_restart_only_settings(&current);
+                *config.write().unwrap_or_else(|e| e.into_inner()) = reloaded;
+                info!("🔄 Configuration reloaded on SIGHUP");
+            }
+            Some(Err(e)) => {
+                error!("Failed to reload config on SIGHUP, keeping previous configuration: {}", e)
+            }
+            // Not reached: the handler is only installed for file-backed configurations
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::EndpointGroup;
+
+    fn inline_config() -> AppConfig {
+        let mut config = AppConfig::default();
+        config.domains = vec!["https://example.com".to_string()];
+        config
+    }
+
+    #[test]
+    fn build_normalizes_supplied_config() {
+        let mut config = inline_config();
+        config.base_path = "/dideban/".to_string();
+        config.endpoints = vec![EndpointGroup {
+            base: "https://api.example.com".to_string(),
+            paths: vec!["/health".to_string()],
+        }];
+
+        let dideban = Dideban::builder().config(config).build().expect("config is valid");
+        assert_eq!(dideban.config().base_path, "/dideban");
+        assert!(dideban.config().domains.contains(&"https://api.example.com/health".to_string()));
+    }
+
+    #[test]
+    fn build_rejects_invalid_supplied_config() {
+        let mut config = inline_config();
+        config.base_path = "dideban".to_string();
+        assert!(matches!(
+            Dideban::builder().config(config).build(),
+            Err(ConfigError::Validation("base_path", _))
+        ));
+
+        let mut config = inline_config();
+        config.interval = 0;
+        assert!(matches!(
+            Dideban::builder().config(config).build(),
+            Err(ConfigError::Validation("interval", _))
+        ));
+
+        let mut config = inline_config();
+        config.web_password = String::new();
+        assert!(matches!(
+            Dideban::builder().config(config).build(),
+            Err(ConfigError::Validation("web_password", _))
+        ));
+    }
+
+    #[test]
+    fn build_accepts_default_config_without_domains() {
+        let config = AppConfig {
+            source: ConfigSource::Defaults("config.toml".into()),
+            ..AppConfig::default()
+        };
+        assert!(config.domains.is_empty());
+
+        let dideban = Dideban::builder().config(config).build().expect("defaults start the server");
+        assert!(matches!(dideban.config().source, ConfigSource::Defaults(_)));
+    }
+}
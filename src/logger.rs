@@ -1,5 +1,82 @@
+use log::kv::{Error as KvError, Key, Value, VisitSource};
 use log::{Level, LevelFilter, Metadata, Record, SetLoggerError};
 use colored::Colorize;
+use serde::Serialize;
+use std::sync::OnceLock;
+use tokio::sync::broadcast;
+
+/// Number of records buffered for live log subscribers before slow ones start missing records.
+const LOG_CHANNEL_CAPACITY: usize = 256;
+
+/// Targets whose records are printed but never published to live subscribers.
+///
+/// These crates log while writing responses, including the `/api/logs/stream` events
+/// themselves, so publishing their records would feed each event back into the stream.
+const UNPUBLISHED_TARGETS: [&str; 2] = ["actix_http", "actix_server"];
+
+/// Broadcast channel that every emitted record is published to for live subscribers.
+static LOG_CHANNEL: OnceLock<broadcast::Sender<LogRecord>> = OnceLock::new();
+
+/// A log record as published to live subscribers (e.g., the `/api/logs/stream` endpoint).
+#[derive(Clone, Serialize)]
+pub struct LogRecord {
+    /// Timestamp in RFC 3339 format.
+    pub ts: String,
+    /// Log level (e.g., "info").
+    #[serde(serialize_with = "serialize_level")]
+    pub level: Level,
+    /// Module path the record originated from.
+    pub target: String,
+    /// Formatted log message.
+    pub msg: String,
+    /// Structured key-value context attached to the record.
+    #[serde(serialize_with = "serialize_fields")]
+    pub fields: Vec<(String, String)>,
+}
+
+/// Serializes key-value pairs as a map (e.g., `{"check_id": "a1b2"}`).
+fn serialize_fields<S: serde::Serializer>(
+    fields: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(fields.iter().map(|(key, value)| (key, value)))
+}
+
+/// Serializes a `Level` as its lowercase name.
+fn serialize_level<S: serde::Serializer>(level: &Level, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&level.as_str().to_lowercase())
+}
+
+/// Returns the sender of the live log channel, creating it on first use.
+fn log_channel() -> &'static broadcast::Sender<LogRecord> {
+    LOG_CHANNEL.get_or_init(|| broadcast::channel(LOG_CHANNEL_CAPACITY).0)
+}
+
+/// Subscribes to records emitted by the `ConsoleLogger` from now on.
+///
+/// Only records that pass the logger's configured level are published, and records
+/// from the HTTP server internals (`UNPUBLISHED_TARGETS`) are left out.
+pub fn subscribe() -> broadcast::Receiver<LogRecord> {
+    log_channel().subscribe()
+}
+
+/// Returns whether records from `target` are published to live subscribers.
+fn is_published(target: &str) -> bool {
+    !UNPUBLISHED_TARGETS.iter().any(|crate_name| {
+        target
+            .strip_prefix(crate_name)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+    })
+}
+
+/// Output format used by the `ConsoleLogger`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable, colored format (e.g., `[2024-01-15 10:00:00] INFO - dideban: message`).
+    Text,
+    /// Machine-readable key=value pairs (e.g., `ts=... level=info target=dideban msg="message"`).
+    Logfmt,
+}
 
 /// A lightweight console logger for the Dideban application.
 ///
@@ -7,29 +84,125 @@ use colored::Colorize;
 /// It implements the `log::Log` trait to integrate with the `log` crate.
 pub struct ConsoleLogger {
     level: LevelFilter,
+    format: LogFormat,
 }
 
 impl ConsoleLogger {
-    /// Creates a new `ConsoleLogger` with the specified log level.
+    /// Creates a new `ConsoleLogger` with the specified log level and output format.
     ///
     /// # Arguments
     /// * `level` - The maximum log level to display (e.g., Error, Warn, Info, Debug, Trace).
-    pub fn new(level: LevelFilter) -> Self {
-        ConsoleLogger { level }
+    /// * `format` - The output format for each log line.
+    pub fn new(level: LevelFilter, format: LogFormat) -> Self {
+        ConsoleLogger { level, format }
     }
 
     /// Initializes the logger and sets it as the global logger.
     ///
     /// # Arguments
     /// * `level` - The maximum log level to display.
+    /// * `format` - The output format for each log line.
     ///
     /// # Returns
     /// * `Ok(())` - Logger initialized successfully.
     /// * `Err(SetLoggerError)` - Failed to set the logger.
-    pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
-        log::set_boxed_logger(Box::new(ConsoleLogger::new(level)))
+    pub fn init(level: LevelFilter, format: LogFormat) -> Result<(), SetLoggerError> {
+        log::set_boxed_logger(Box::new(ConsoleLogger::new(level, format)))
             .map(|()| log::set_max_level(level))
     }
+
+    /// Prints a record in the human-readable, colored format.
+    fn log_text(&self, record: &Record) {
+        // Choose color based on log level
+        let level_str = match record.level() {
+            Level::Error => "ERROR".red().bold(),
+            Level::Warn => "WARN".yellow(),
+            Level::Info => "INFO".green(),
+            Level::Debug => "DEBUG".blue(),
+            Level::Trace => "TRACE".cyan(),
+        };
+
+        // ISO-8601 Datetime pattern
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+        let context: String = key_values(record)
+            .into_iter()
+            .map(|(key, value)| format!(" {}={}", key, value))
+            .collect();
+        println!(
+            "[{}] {} - {}: {}{}",
+            timestamp,
+            level_str,
+            record.target(),
+            record.args(),
+            context
+        );
+    }
+
+    /// Prints a record as logfmt key=value pairs.
+    fn log_logfmt(&self, record: &Record) {
+        let timestamp = chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z");
+        let context: String = key_values(record)
+            .into_iter()
+            .map(|(key, value)| format!(" {}={}", key, logfmt_value(&value)))
+            .collect();
+        println!(
+            "ts={} level={} target={} msg={}{}",
+            timestamp,
+            record.level().as_str().to_lowercase(),
+            logfmt_value(record.target()),
+            logfmt_value(&record.args().to_string()),
+            context
+        );
+    }
+}
+
+/// Collects structured key-value pairs attached to a log record.
+///
+/// Callers attach context such as a per-check correlation id with
+/// `info!(check_id = id; "message")`, and it is rendered after the message.
+fn key_values(record: &Record) -> Vec<(String, String)> {
+    struct Collect(Vec<(String, String)>);
+
+    impl<'kvs> VisitSource<'kvs> for Collect {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), KvError> {
+            self.0.push((key.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    let mut collect = Collect(Vec::new());
+    // Collect never fails, so the result can be ignored.
+    let _ = record.key_values().visit(&mut collect);
+    collect.0
+}
+
+/// Formats a value for logfmt output.
+///
+/// Values containing spaces, `=`, quotes, or control characters (and empty values)
+/// are wrapped in double quotes with quotes, backslashes, and newlines escaped.
+fn logfmt_value(value: &str) -> String {
+    let needs_quoting = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '=' || c == '"' || c.is_control());
+    if !needs_quoting {
+        return value.to_string();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
 }
 
 impl log::Log for ConsoleLogger {
@@ -40,30 +213,108 @@ impl log::Log for ConsoleLogger {
 
     /// Logs a message to the console.
     ///
-    /// Formats the message with timestamp, level, target, and message content.
+    /// Formats the message with timestamp, level, target, and message content
+    /// according to the configured `LogFormat`, then publishes it to live subscribers.
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            // Choose color based on log level
-            let level_str = match record.level() {
-                Level::Error => "ERROR".red().bold(),
-                Level::Warn => "WARN".yellow(),
-                Level::Info => "INFO".green(),
-                Level::Debug => "DEBUG".blue(),
-                Level::Trace => "TRACE".cyan(),
-            };
-
-            // ISO-8601 Datetime pattern
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            println!(
-                "[{}] {} - {}: {}",
-                timestamp,
-                level_str,
-                record.target(),
-                record.args()
-            );
+            match self.format {
+                LogFormat::Text => self.log_text(record),
+                LogFormat::Logfmt => self.log_logfmt(record),
+            }
+
+            // Publish to live subscribers, skipping the formatting work when there are none
+            let channel = log_channel();
+            if channel.receiver_count() > 0 && is_published(record.target()) {
+                // Sending only fails when all receivers were dropped in the meantime.
+                let _ = channel.send(LogRecord {
+                    ts: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, false),
+                    level: record.level(),
+                    target: record.target().to_string(),
+                    msg: record.args().to_string(),
+                    fields: key_values(record),
+                });
+            }
         }
     }
 
     /// Flushes the logger (no-op for console logging).
     fn flush(&self) {}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Log;
+
+    /// Logs a trace record from `target` through `logger`.
+    fn log_trace(logger: &ConsoleLogger, target: &str, message: &str) {
+        logger.log(
+            &Record::builder()
+                .level(Level::Trace)
+                .target(target)
+                .args(format_args!("{}", message))
+                .build(),
+        );
+    }
+
+    #[test]
+    fn is_published_skips_http_server_targets() {
+        assert!(!is_published("actix_http"));
+        assert!(!is_published("actix_http::h1::dispatcher"));
+        assert!(!is_published("actix_server::worker"));
+        assert!(is_published("dideban::api"));
+        assert!(is_published("actix_web::middleware::logger"));
+        assert!(is_published("actix_httpx"));
+    }
+
+    #[test]
+    fn trace_subscription_does_not_feed_back_server_records() {
+        let logger = ConsoleLogger::new(LevelFilter::Trace, LogFormat::Logfmt);
+        let mut receiver = subscribe();
+        log_trace(&logger, "dideban_logger_test", "first record");
+
+        // Stand in for the SSE handler: writing each event makes the server log again
+        let mut received = Vec::new();
+        while let Ok(record) = receiver.try_recv() {
+            received.push(record.target);
+            assert!(received.len() <= 1, "records fed back into the stream: {:?}", received);
+            log_trace(&logger, "actix_http::h1::dispatcher", "wrote event");
+            log_trace(&logger, "actix_server::worker", "wrote event");
+        }
+        assert_eq!(received, ["dideban_logger_test"]);
+    }
+
+    #[test]
+    fn logfmt_value_leaves_plain_values_unquoted() {
+        assert_eq!(logfmt_value("dideban::api"), "dideban::api");
+    }
+
+    #[test]
+    fn logfmt_value_quotes_empty_values() {
+        assert_eq!(logfmt_value(""), "\"\"");
+    }
+
+    #[test]
+    fn logfmt_value_quotes_values_with_spaces() {
+        assert_eq!(logfmt_value("Server running"), "\"Server running\"");
+    }
+
+    #[test]
+    fn logfmt_value_quotes_values_with_equals() {
+        assert_eq!(logfmt_value("a=b"), "\"a=b\"");
+    }
+
+    #[test]
+    fn logfmt_value_escapes_quotes_and_backslashes() {
+        assert_eq!(logfmt_value(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(logfmt_value(r"C:\Program Files"), r#""C:\\Program Files""#);
+        // Unquoted values are read literally, so a lone backslash needs no escaping
+        assert_eq!(logfmt_value(r"C:\dideban"), r"C:\dideban");
+    }
+
+    #[test]
+    fn logfmt_value_escapes_newlines_and_tabs() {
+        assert_eq!(logfmt_value("line1\nline2"), r#""line1\nline2""#);
+        assert_eq!(logfmt_value("a\r\tb"), r#""a\r\tb""#);
+    }
+}
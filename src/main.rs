@@ -1,20 +1,69 @@
-use actix_web::{App, HttpServer};
+use dideban::config::AppConfig;
+use dideban::logger::{ConsoleLogger, LogFormat};
+use dideban::Dideban;
 use log::{debug, info, LevelFilter};
+use std::path::PathBuf;
+use std::process::ExitCode;
 
-mod config;
-mod logger;
-use config::AppConfig;
-use logger::ConsoleLogger;
+/// Validates a configuration file and prints every problem found.
+///
+/// Backs the `dideban --validate [config.toml]` command. Without a path,
+/// the default config location is checked.
+///
+/// # Returns
+/// * `ExitCode::SUCCESS` - The configuration is valid.
+/// * `ExitCode::FAILURE` - The file could not be read or parsed, or has validation problems.
+fn validate_command(path: Option<String>) -> ExitCode {
+    let path = match path.map(PathBuf::from).map_or_else(AppConfig::default_path, Ok) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("✘ {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match AppConfig::validate_file(&path) {
+        Ok(_) => {
+            println!("✔ {} is valid", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(errors) => {
+            eprintln!("✘ {} has {} problem(s):", path.display(), errors.len());
+            for error in errors {
+                eprintln!("  - {}", error);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
 
 /// Main entry point for the Dideban application.
 ///
-/// Initializes the custom console logger based on the configured log level,
-/// loads the configuration, logs configuration details in debug mode,
-/// and starts the Actix Web server without routes.
+/// Dispatches `--validate`, otherwise runs the server.
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    if let Some("--validate") = args.next().as_deref() {
+        return validate_command(args.next());
+    }
+
+    run()
+}
+
+/// Loads the configuration, sets up logging, and runs the server until shutdown.
+///
+/// # Returns
+/// * `ExitCode::SUCCESS` - The server shut down cleanly.
+/// * `ExitCode::FAILURE` - The configuration could not be loaded, or the server failed.
 #[actix_web::main]
-async fn main() -> std::io::Result<()> {
+async fn run() -> ExitCode {
     // Load configuration
-    let config = AppConfig::from_file().expect("Failed to load config");
+    let config = match AppConfig::from_file() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("✘ {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
 
     // Initialize logger with configured log level
     let log_level = match config.log_level.as_str() {
@@ -25,34 +74,65 @@ async fn main() -> std::io::Result<()> {
         "trace" => LevelFilter::Trace,
         _ => LevelFilter::Info, // Fallback to Info if invalid
     };
-    ConsoleLogger::init(log_level).expect("Failed to initialize logger");
+    let log_format = match config.log_format.as_str() {
+        "logfmt" => LogFormat::Logfmt,
+        _ => LogFormat::Text,
+    };
+    ConsoleLogger::init(log_level, log_format).expect("Failed to initialize logger");
+
+    let dideban = match Dideban::builder().config(config).build() {
+        Ok(dideban) => dideban,
+        Err(e) => {
+            eprintln!("✘ {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let config = dideban.config();
+
+    // Log startup banner with a configuration summary (no secrets)
+    let notifiers: Vec<&str> = [("bale", config.enable_bale)]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect();
+    info!("👁 Dideban v{}", env!("CARGO_PKG_VERSION"));
+    info!("  - domains: {} monitored", config.domains.len());
+    info!("  - interval: {} seconds", config.interval);
+    info!(
+        "  - notifiers: {}",
+        if notifiers.is_empty() { "none".to_string() } else { notifiers.join(", ") }
+    );
+    info!("  - db_path: {}", config.db_path);
+    info!("  - log_level: {} ({})", config.log_level, config.log_format);
 
     // Log configuration details in debug mode
     if config.log_level == "debug" {
         debug!("Configuration loaded:");
         debug!("  - domains: {:?}", config.domains);
+        debug!("  - endpoints: {} group(s)", config.endpoints.len());
         debug!("  - interval: {} seconds", config.interval);
         debug!("  - server_host: {}", config.server_host);
         debug!("  - server_port: {}", config.server_port);
+        debug!("  - server_keep_alive: {} seconds", config.server_keep_alive);
+        debug!("  - server_client_timeout: {} seconds", config.server_client_timeout);
+        debug!("  - base_path: {:?}", config.base_path);
         debug!("  - enable_bale: {}", config.enable_bale);
-        debug!("  - bale_token: {}", config.bale_token);
+        debug!("  - bale_token: [hidden]");
+        debug!("  - bale_token_file: {:?}", config.bale_token_file);
         debug!("  - bale_chat_id: {}", config.bale_chat_id);
         debug!("  - web_username: {}", config.web_username);
         debug!("  - web_password: [hidden]");
+        debug!("  - web_password_file: {:?}", config.web_password_file);
         debug!("  - db_path: {}", config.db_path);
         debug!("  - log_level: {}", config.log_level);
+        debug!("  - log_format: {}", config.log_format);
     }
 
-    // Prepare server address
-    let bind_address = format!("{}:{}", config.server_host, config.server_port);
-
-    // Log server start message
-    info!("🚀 Server running at http://{}/", bind_address);
-
-    // Start Actix Web server without routes
-    HttpServer::new(|| App::new())
-        .workers(1)
-        .bind(&bind_address)?
-        .run()
-        .await
+    // Start the Actix Web server
+    match dideban.run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("✘ {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }